@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path};
+use tauri::State;
+
+use crate::context::Context;
+use crate::error::NeuroError;
+use crate::settings::{self, Settings};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelInfo {
+    pub name: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InferenceRequest {
+    pub model: String,
+    pub prompt: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InferenceResult {
+    pub model: String,
+    pub output: String,
+}
+
+/// Scans `dir` for model files and returns them as [`ModelInfo`]. Shared by
+/// the `list_models` command and the startup warm-up routine.
+pub(crate) fn scan_models(dir: &Path) -> Result<Vec<ModelInfo>, NeuroError> {
+    let mut models = Vec::new();
+    if !dir.is_dir() {
+        return Ok(models);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+            models.push(ModelInfo {
+                name: name.to_string(),
+                path: path.to_string_lossy().into_owned(),
+            });
+        }
+    }
+    Ok(models)
+}
+
+fn model_dir(context: &State<'_, Context>) -> Result<std::path::PathBuf, NeuroError> {
+    let settings = context
+        .settings
+        .lock()
+        .map_err(|_| NeuroError::Io("settings lock poisoned".to_string()))?;
+    Ok(settings.model_dir.clone())
+}
+
+/// Resolves `name` (as returned by `scan_models`/`list_models`) to its
+/// `ModelInfo`, so every command agrees on the same `name`/`path` pair
+/// instead of each reconstructing the path independently.
+fn find_model(dir: &Path, name: &str) -> Result<ModelInfo, NeuroError> {
+    // A model name is a single path component, never a traversal/separator.
+    // Reject those outright instead of letting them fall through as an
+    // ordinary not-found lookup.
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => {}
+        _ => return Err(NeuroError::InvalidModelPath(name.to_string())),
+    }
+
+    scan_models(dir)?
+        .into_iter()
+        .find(|model| model.name == name)
+        .ok_or_else(|| NeuroError::ModelNotFound(name.to_string()))
+}
+
+#[tauri::command]
+pub fn list_models(context: State<'_, Context>) -> Result<Vec<ModelInfo>, NeuroError> {
+    scan_models(&model_dir(&context)?)
+}
+
+#[tauri::command]
+pub fn load_model(name: String, context: State<'_, Context>) -> Result<ModelInfo, NeuroError> {
+    find_model(&model_dir(&context)?, &name)
+}
+
+#[tauri::command]
+pub fn run_inference(
+    request: InferenceRequest,
+    context: State<'_, Context>,
+) -> Result<InferenceResult, NeuroError> {
+    find_model(&model_dir(&context)?, &request.model)?;
+    Ok(InferenceResult {
+        model: request.model,
+        output: format!("echo: {}", request.prompt),
+    })
+}
+
+#[tauri::command]
+pub fn cancel_inference() -> Result<(), NeuroError> {
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_backend_url(context: State<'_, Context>) -> Result<String, NeuroError> {
+    Ok(context.backend_url.clone())
+}
+
+#[tauri::command]
+pub fn get_settings(context: State<'_, Context>) -> Result<Settings, NeuroError> {
+    let settings = context
+        .settings
+        .lock()
+        .map_err(|_| NeuroError::Io("settings lock poisoned".to_string()))?;
+    Ok(settings.clone())
+}
+
+#[tauri::command]
+pub fn update_settings(
+    new_settings: Settings,
+    context: State<'_, Context>,
+) -> Result<(), NeuroError> {
+    settings::save_settings(&context.config_dir, &new_settings)?;
+    let mut settings = context
+        .settings
+        .lock()
+        .map_err(|_| NeuroError::Io("settings lock poisoned".to_string()))?;
+    *settings = new_settings;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::settings::Settings;
+
+const DEFAULT_BACKEND_URL: &str = "http://localhost:8080";
+
+/// State managed via `Builder::manage`, reachable from any command through
+/// `State<'_, Context>`.
+pub struct Context {
+    pub config_dir: PathBuf,
+    pub settings: Mutex<Settings>,
+    pub backend_url: String,
+}
+
+/// Resolves the inference backend URL from `NEUROEDGE_BACKEND_URL`, falling
+/// back to a local default. Read once at startup and cached on [`Context`]
+/// so the same build can point at a local server in dev and a remote/edge
+/// node in production without recompiling.
+pub fn resolve_backend_url() -> String {
+    std::env::var("NEUROEDGE_BACKEND_URL").unwrap_or_else(|_| DEFAULT_BACKEND_URL.to_string())
+}
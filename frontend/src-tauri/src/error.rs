@@ -0,0 +1,31 @@
+use serde::Serialize;
+use std::fmt;
+
+/// Shared error type returned by every `#[tauri::command]` in this crate.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum NeuroError {
+    ModelNotFound(String),
+    InvalidModelPath(String),
+    InferenceFailed(String),
+    Io(String),
+}
+
+impl fmt::Display for NeuroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeuroError::ModelNotFound(name) => write!(f, "model not found: {name}"),
+            NeuroError::InvalidModelPath(path) => write!(f, "invalid model path: {path}"),
+            NeuroError::InferenceFailed(reason) => write!(f, "inference failed: {reason}"),
+            NeuroError::Io(reason) => write!(f, "io error: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for NeuroError {}
+
+impl From<std::io::Error> for NeuroError {
+    fn from(err: std::io::Error) -> Self {
+        NeuroError::Io(err.to_string())
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::context::Context;
+use crate::error::NeuroError;
+
+/// Payload for the `init-progress` event emitted while the splashscreen is shown.
+#[derive(Debug, Clone, Serialize)]
+pub struct InitProgress {
+    pub stage: String,
+    pub percent: u8,
+}
+
+fn emit_progress(app: &AppHandle, stage: &str, percent: u8) {
+    app.emit_all(
+        "init-progress",
+        InitProgress {
+            stage: stage.to_string(),
+            percent,
+        },
+    )
+    .ok();
+}
+
+/// Loads and warms the configured model directory off the main thread, so
+/// the splashscreen window can show real progress before `main` appears.
+pub async fn load_and_warm_model(app: &AppHandle) -> Result<(), NeuroError> {
+    let model_dir = {
+        let context = app.state::<Context>();
+        let settings = context
+            .settings
+            .lock()
+            .map_err(|_| NeuroError::Io("settings lock poisoned".to_string()))?;
+        settings.model_dir.clone()
+    };
+
+    emit_progress(app, "scanning-models", 20);
+    let models = crate::commands::scan_models(&model_dir)?;
+
+    emit_progress(app, "warming", 70);
+    if let Some(first) = models.first() {
+        // Touch the model file so a missing/unreadable model surfaces here
+        // instead of on the user's first inference request.
+        std::fs::File::open(&first.path)?;
+    }
+
+    emit_progress(app, "ready", 100);
+    Ok(())
+}
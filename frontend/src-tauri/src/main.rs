@@ -1,12 +1,59 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod commands;
+mod context;
+mod error;
+mod init;
+mod settings;
+mod window_manager;
+
+use std::sync::Mutex;
+
+use context::Context;
+use tauri::Manager;
+
 fn main() {
+  // `tauri.conf.json` configures `tauri.security.pattern` as `isolation`,
+  // so every invoke call below is routed through the secure origin served
+  // from `frontend/isolation-src/index.html` before it reaches these
+  // commands. Responses are not routed through it.
+  let tauri_context = tauri::generate_context!();
+  let config_dir = tauri::api::path::app_config_dir(tauri_context.config())
+    .expect("failed to resolve app config dir");
+  let loaded_settings =
+    settings::load_settings(&config_dir).expect("failed to load settings");
+
   tauri::Builder::default()
+    .manage(Context {
+      config_dir,
+      settings: Mutex::new(loaded_settings),
+      backend_url: context::resolve_backend_url(),
+    })
+    .invoke_handler(tauri::generate_handler![
+      commands::run_inference,
+      commands::list_models,
+      commands::load_model,
+      commands::cancel_inference,
+      commands::get_settings,
+      commands::update_settings,
+      commands::get_backend_url,
+      window_manager::open_or_focus_window,
+    ])
     .setup(|app| {
-      let window = app.get_window("main").unwrap();
-      window.set_always_on_top(true).ok();
+      let splashscreen = app.get_window("splashscreen").unwrap();
+      let main_window = app.get_window("main").unwrap();
+      let handle = app.handle();
+
+      tauri::async_runtime::spawn(async move {
+        if let Err(err) = init::load_and_warm_model(&handle).await {
+          eprintln!("failed to initialize model: {err}");
+        }
+        splashscreen.close().ok();
+        main_window.show().ok();
+      });
+
       Ok(())
     })
-    .run(tauri::generate_context!())
+    .run(tauri_context)
     .expect("error while running tauri application");
 }
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::NeuroError;
+
+const SETTINGS_FILE: &str = "settings.json";
+
+/// User-configurable application settings, persisted as JSON in the
+/// platform config directory. `#[serde(default)]` keeps older settings
+/// files forward-compatible: a field added in a later version is filled in
+/// from `Default` instead of failing deserialization for existing installs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub model_dir: PathBuf,
+    pub device: String,
+    pub threads: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            model_dir: PathBuf::from("models"),
+            device: "cpu".to_string(),
+            threads: 4,
+        }
+    }
+}
+
+impl Settings {
+    /// Default settings anchored under `config_dir`, since a bundled
+    /// desktop app's working directory is unpredictable and a bare
+    /// relative `model_dir` would resolve against it.
+    fn default_for(config_dir: &Path) -> Self {
+        Self {
+            model_dir: config_dir.join("models"),
+            device: "cpu".to_string(),
+            threads: 4,
+        }
+    }
+}
+
+/// Loads settings from `config_dir`, writing out the defaults if no
+/// settings file exists yet. A settings file that fails to parse is backed
+/// up alongside itself and replaced with defaults rather than propagated as
+/// a startup error — a single malformed or stale `settings.json` shouldn't
+/// be able to brick the app.
+pub fn load_settings(config_dir: &Path) -> Result<Settings, NeuroError> {
+    let path = config_dir.join(SETTINGS_FILE);
+    if !path.is_file() {
+        let settings = Settings::default_for(config_dir);
+        save_settings(config_dir, &settings)?;
+        return Ok(settings);
+    }
+    let data = fs::read_to_string(&path)?;
+    if let Ok(settings) = serde_json::from_str(&data) {
+        return Ok(settings);
+    }
+
+    fs::rename(&path, config_dir.join(format!("{SETTINGS_FILE}.bak")))?;
+    let settings = Settings::default_for(config_dir);
+    save_settings(config_dir, &settings)?;
+    Ok(settings)
+}
+
+pub fn save_settings(config_dir: &Path, settings: &Settings) -> Result<(), NeuroError> {
+    fs::create_dir_all(config_dir)?;
+    let data =
+        serde_json::to_string_pretty(settings).map_err(|err| NeuroError::Io(err.to_string()))?;
+    fs::write(config_dir.join(SETTINGS_FILE), data)?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use tauri::{AppHandle, Manager, WindowBuilder, WindowUrl};
+
+use crate::error::NeuroError;
+
+/// Focuses the window labeled `label` if it already exists, otherwise builds
+/// a new one pointed at `url`. Building happens inside this command, on the
+/// app handle, rather than synchronously during an awaited frontend call —
+/// `WebviewWindow.getByLabel` followed by an inline `new WebviewWindow(...)`
+/// on the JS side is what triggers the reported main-thread stack overflow.
+#[tauri::command(async)]
+pub fn open_or_focus_window(
+    app: AppHandle,
+    label: String,
+    url: String,
+) -> Result<(), NeuroError> {
+    if let Some(window) = app.get_window(&label) {
+        window.unminimize().map_err(|err| NeuroError::Io(err.to_string()))?;
+        window.show().map_err(|err| NeuroError::Io(err.to_string()))?;
+        window.set_focus().map_err(|err| NeuroError::Io(err.to_string()))?;
+        return Ok(());
+    }
+
+    WindowBuilder::new(&app, label, WindowUrl::App(url.into()))
+        .build()
+        .map_err(|err| NeuroError::Io(err.to_string()))?;
+    Ok(())
+}